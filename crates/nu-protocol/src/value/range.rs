@@ -23,29 +23,71 @@ impl Range {
         to: Value,
         operator: &RangeOperator,
     ) -> Result<Range, ShellError> {
-        // Select from & to values if they're not specified
-        // TODO: Replace the placeholder values with proper min/max based on data type
-        let from = if let Value::Nothing { .. } = from {
-            Value::Int {
-                val: 0i64,
-                span: Span::unknown(),
-            }
+        // Select the to value if it's not specified, using the numeric extreme
+        // that matches the endpoint type rather than an arbitrary bound:
+        // `i64::MIN`/`i64::MAX` for integers, `f64::NEG_INFINITY`/`f64::INFINITY`
+        // for floats. `RangeIterator` treats these as genuinely unbounded. A
+        // missing from is handled separately below.
+        let is_float = matches!(from, Value::Float { .. })
+            || matches!(to, Value::Float { .. })
+            || matches!(next, Value::Float { .. });
+
+        // An open endpoint descends only when the step explicitly points down
+        // from the concrete endpoint; default to ascending otherwise.
+        let descending = if !matches!(from, Value::Nothing { .. }) {
+            matches!(next.lt(expr_span, &from), Ok(Value::Bool { val: true, .. }))
+        } else if !matches!(to, Value::Nothing { .. }) {
+            matches!(next.lt(expr_span, &to), Ok(Value::Bool { val: true, .. }))
         } else {
-            from
+            false
         };
 
-        let to = if let Value::Nothing { .. } = to {
-            if let Ok(Value::Bool { val: true, .. }) = next.lt(expr_span, &from) {
-                Value::Int {
-                    val: -100i64,
+        let extreme = |upper: bool| {
+            // Flip the chosen extreme when the range runs downward.
+            let upper = upper ^ descending;
+            match (is_float, upper) {
+                (true, true) => Value::Float {
+                    val: f64::INFINITY,
+                    span: Span::unknown(),
+                },
+                (true, false) => Value::Float {
+                    val: f64::NEG_INFINITY,
+                    span: Span::unknown(),
+                },
+                (false, true) => Value::Int {
+                    val: i64::MAX,
+                    span: Span::unknown(),
+                },
+                (false, false) => Value::Int {
+                    val: i64::MIN,
+                    span: Span::unknown(),
+                },
+            }
+        };
+
+        // A missing `from` intentionally stays at `0` rather than a numeric
+        // extreme: `..5` must mean `0..5`, so the low end is a concrete start,
+        // not "unbounded". Only the open `to` bound was the arbitrary
+        // placeholder this change replaces. The zero is typed to match the
+        // range so `..5.0` yields floats instead of `Int` values.
+        let from = if let Value::Nothing { .. } = from {
+            if is_float {
+                Value::Float {
+                    val: 0.0,
                     span: Span::unknown(),
                 }
             } else {
                 Value::Int {
-                    val: 100i64,
+                    val: 0i64,
                     span: Span::unknown(),
                 }
             }
+        } else {
+            from
+        };
+
+        let to = if let Value::Nothing { .. } = to {
+            extreme(true)
         } else {
             to
         };
@@ -53,7 +95,9 @@ impl Range {
         // Check if the range counts up or down
         let moves_up = matches!(from.lte(expr_span, &to), Ok(Value::Bool { val: true, .. }));
 
-        // Convert the next value into the inctement
+        // Convert the next value into the increment. Char ranges step by a
+        // signed codepoint delta, so the increment is an `Int` either way.
+        let over_chars = matches!(Rangeable::infer(&from, &to), Some(Rangeable::Char));
         let incr = if let Value::Nothing { .. } = next {
             if moves_up {
                 Value::Int {
@@ -66,6 +110,14 @@ impl Range {
                     span: Span::unknown(),
                 }
             }
+        } else if over_chars {
+            match (as_char(&next), as_char(&from)) {
+                (Some(next), Some(from)) => Value::Int {
+                    val: next as i64 - from as i64,
+                    span: Span::unknown(),
+                },
+                _ => return Err(ShellError::CannotCreateRange(expr_span)),
+            }
         } else {
             next.sub(operator.next_op_span, &from)?
         };
@@ -125,6 +177,9 @@ pub struct RangeIterator {
     moves_up: bool,
     incr: Value,
     done: bool,
+    // Back cursor used by `next_back`; lazily seeded on the first reverse step
+    // with the last in-range element so reverse iteration stays O(1) in memory.
+    back: Option<Value>,
 }
 
 impl RangeIterator {
@@ -150,7 +205,130 @@ impl RangeIterator {
             is_end_inclusive: matches!(range.inclusion, RangeInclusion::Inclusive),
             done: false,
             incr: range.incr,
+            back: None,
+        }
+    }
+
+    // Seed the back cursor with the last element that falls inside the range,
+    // computed as `curr + k * incr` where `k = floor((end - curr) / incr)`.
+    // The result is clamped back by one step if it would pass `end` (or land on
+    // an exclusive endpoint), so the cursor always names a yielded element.
+    fn last_in_range(&self) -> Result<Value, ShellError> {
+        let span = self.span;
+
+        // Char ranges cannot use the arithmetic `Value` ops, so walk the
+        // codepoints directly: the last element is `curr + k * incr`.
+        if let (Some(curr), Some(end)) = (as_char(&self.curr), as_char(&self.end)) {
+            let incr = match &self.incr {
+                Value::Int { val, .. } => *val,
+                _ => return Err(ShellError::CannotCreateRange(span)),
+            };
+
+            let mut k = (end as i64 - curr as i64) / incr;
+            loop {
+                let cp = curr as i64 + k * incr;
+                let past_end = if self.is_end_inclusive {
+                    (self.moves_up && cp > end as i64) || (!self.moves_up && cp < end as i64)
+                } else {
+                    (self.moves_up && cp >= end as i64) || (!self.moves_up && cp <= end as i64)
+                };
+
+                if !past_end {
+                    let back = u32::try_from(cp)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| ShellError::CannotCreateRange(span))?;
+                    return Ok(Value::String {
+                        val: back.to_string(),
+                        span,
+                    });
+                }
+
+                k -= 1;
+            }
+        }
+
+        let diff = self.end.sub(span, &self.curr)?;
+        let steps = diff.div(span, &self.incr)?;
+        // Snap the step count down onto the forward grid. Integer division
+        // already truncates, but float division must be floored explicitly so
+        // reverse iteration lands on the same elements forward iteration visits.
+        let steps = if let Value::Float { val, span } = steps {
+            Value::Float {
+                val: val.floor(),
+                span,
+            }
+        } else {
+            steps
+        };
+        let offset = steps.mul(span, &self.incr)?;
+        let mut back = self.curr.add(span, &offset)?;
+
+        loop {
+            let past_end = match compare_numbers(&back, &self.end) {
+                Some(Ordering::Greater) => self.moves_up,
+                Some(Ordering::Less) => !self.moves_up,
+                Some(Ordering::Equal) => !self.is_end_inclusive,
+                None => false,
+            };
+
+            if !past_end {
+                break;
+            }
+
+            back = back.sub(span, &self.incr)?;
+        }
+
+        Ok(back)
+    }
+
+    // Advance `curr` by `incr`, returning `Ok(None)` when another step would
+    // run past the representable range of the underlying number instead of
+    // wrapping (`i64`) or saturating to infinity (`f64`). Callers yield the
+    // current element and stop when this happens.
+    fn checked_step(&self) -> Result<Option<Value>, ShellError> {
+        // Char ranges advance by codepoint; stop if the next codepoint is not
+        // a valid Unicode scalar value.
+        if let Some(curr) = as_char(&self.curr) {
+            let incr = match &self.incr {
+                Value::Int { val, .. } => *val,
+                _ => return Ok(None),
+            };
+
+            let next = (curr as i64)
+                .checked_add(incr)
+                .and_then(|cp| u32::try_from(cp).ok())
+                .and_then(char::from_u32);
+
+            return Ok(next.map(|c| Value::String {
+                val: c.to_string(),
+                span: self.span,
+            }));
         }
+
+        if let (Value::Int { val: curr, .. }, Value::Int { val: incr, .. }) =
+            (&self.curr, &self.incr)
+        {
+            if curr.checked_add(*incr).is_none() {
+                return Ok(None);
+            }
+        }
+
+        let next = self.curr.add(self.span, &self.incr)?;
+
+        if let Value::Float { val, .. } = &next {
+            if !val.is_finite() {
+                return Ok(None);
+            }
+        }
+
+        // A step that no longer moves the cursor (float precision exhausted)
+        // would otherwise loop forever.
+        if matches!(compare_numbers(&next, &self.curr), Some(Ordering::Equal)) {
+            return Ok(None);
+        }
+
+        Ok(Some(next))
     }
 
     pub fn contains(&self, x: &Value) -> bool {
@@ -170,6 +348,49 @@ impl RangeIterator {
     }
 }
 
+/// The kind of scalar a range steps over.
+///
+/// Borrowed from the "rangeable value" idea: any ordered scalar that has a
+/// successor/predecessor and a comparison can back a range. Today that is the
+/// numeric types, stepped through the usual arithmetic `Value` ops, plus
+/// single-character strings, stepped by Unicode codepoint.
+enum Rangeable {
+    Number,
+    Char,
+}
+
+impl Rangeable {
+    /// Classify a pair of endpoints, or `None` if they are not an orderable,
+    /// steppable scalar pair.
+    fn infer(from: &Value, to: &Value) -> Option<Rangeable> {
+        match (from, to) {
+            (
+                Value::Int { .. } | Value::Float { .. } | Value::Nothing { .. },
+                Value::Int { .. } | Value::Float { .. } | Value::Nothing { .. },
+            ) => Some(Rangeable::Number),
+            (Value::String { .. }, Value::String { .. })
+                if as_char(from).is_some() && as_char(to).is_some() =>
+            {
+                Some(Rangeable::Char)
+            }
+            _ => None,
+        }
+    }
+}
+
+// A single-character string as its `char`, or `None` for anything else.
+fn as_char(val: &Value) -> Option<char> {
+    if let Value::String { val, .. } = val {
+        let mut chars = val.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
 fn compare_numbers(val: &Value, other: &Value) -> Option<Ordering> {
     match (val, other) {
         (Value::Int { val, .. }, Value::Int { val: other, .. }) => Some(val.cmp(other)),
@@ -180,6 +401,10 @@ fn compare_numbers(val: &Value, other: &Value) -> Option<Ordering> {
         (Value::Int { val, .. }, Value::Float { val: other, .. }) => {
             compare_floats(*val as f64, *other)
         }
+        (Value::String { .. }, Value::String { .. }) => match (as_char(val), as_char(other)) {
+            (Some(a), Some(b)) => Some((a as u32).cmp(&(b as u32))),
+            _ => None,
+        },
         _ => None,
     }
 }
@@ -203,6 +428,43 @@ impl Iterator for RangeIterator {
             return None;
         }
 
+        // Once `next_back` has seeded the back cursor, the front and back
+        // cursors iterate toward each other; stop the moment they cross.
+        if let Some(back) = &self.back {
+            match compare_numbers(&self.curr, back) {
+                Some(Ordering::Greater) if self.moves_up => {
+                    self.done = true;
+                    return None;
+                }
+                Some(Ordering::Less) if !self.moves_up => {
+                    self.done = true;
+                    return None;
+                }
+                None => {
+                    self.done = true;
+                    return Some(Value::Error {
+                        error: ShellError::CannotCreateRange(self.span),
+                    });
+                }
+                _ => {}
+            }
+
+            return match self.checked_step() {
+                Ok(Some(mut next)) => {
+                    std::mem::swap(&mut self.curr, &mut next);
+                    Some(next)
+                }
+                Ok(None) => {
+                    self.done = true;
+                    Some(self.curr.clone())
+                }
+                Err(error) => {
+                    self.done = true;
+                    Some(Value::Error { error })
+                }
+            };
+        }
+
         let ordering = if matches!(self.end, Value::Nothing { .. }) {
             Some(Ordering::Less)
         } else {
@@ -226,21 +488,261 @@ impl Iterator for RangeIterator {
 
         if (ordering == desired_ordering) || (self.is_end_inclusive && ordering == Ordering::Equal)
         {
-            let next_value = self.curr.add(self.span, &self.incr);
+            match self.checked_step() {
+                Ok(Some(mut next)) => {
+                    std::mem::swap(&mut self.curr, &mut next);
+                    Some(next)
+                }
+                Ok(None) => {
+                    // A further step would overflow/saturate the endpoint type;
+                    // emit the last representable element, then terminate.
+                    self.done = true;
+                    Some(self.curr.clone())
+                }
+                Err(error) => {
+                    self.done = true;
+                    Some(Value::Error { error })
+                }
+            }
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
 
-            let mut next = match next_value {
-                Ok(result) => result,
+        // Exact counts are only meaningful for integer ranges; everything else
+        // (floats in particular) falls back to the uninformative default.
+        let incr = match &self.incr {
+            Value::Int { val, .. } => *val,
+            _ => return (0, None),
+        };
+        let curr = match &self.curr {
+            Value::Int { val, .. } => *val,
+            _ => return (0, None),
+        };
+        let end = match self.back.as_ref().unwrap_or(&self.end) {
+            // Unbounded ranges are represented with an `i64::MAX` (upward) or
+            // `i64::MIN` (downward) end.
+            Value::Int { val, .. } if *val == i64::MAX || *val == i64::MIN => {
+                return (usize::MAX, None)
+            }
+            Value::Int { val, .. } => *val,
+            _ => return (0, None),
+        };
 
+        // A seeded back cursor always names an element that will still be
+        // yielded, so the back side is effectively inclusive regardless of the
+        // range's own end-inclusivity.
+        let inclusive = self.is_end_inclusive || self.back.is_some();
+
+        let diff = end.saturating_sub(curr);
+        let mut remaining = diff / incr;
+        if inclusive && diff % incr == 0 {
+            remaining = remaining.saturating_add(1);
+        }
+        let remaining = remaining.max(0) as usize;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for RangeIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.back.is_none() {
+            // An open-ended range has no last element to walk back from.
+            if matches!(self.end, Value::Nothing { .. }) {
+                self.done = true;
+                return None;
+            }
+
+            match self.last_in_range() {
+                Ok(back) => self.back = Some(back),
                 Err(error) => {
                     self.done = true;
                     return Some(Value::Error { error });
                 }
+            }
+        }
+
+        let back = self.back.as_ref().expect("back cursor was just seeded");
+
+        // Stop once the back cursor has crossed the front cursor.
+        match compare_numbers(back, &self.curr) {
+            Some(Ordering::Less) if self.moves_up => {
+                self.done = true;
+                return None;
+            }
+            Some(Ordering::Greater) if !self.moves_up => {
+                self.done = true;
+                return None;
+            }
+            None => {
+                self.done = true;
+                return Some(Value::Error {
+                    error: ShellError::CannotCreateRange(self.span),
+                });
+            }
+            _ => {}
+        }
+
+        let yielded = back.clone();
+
+        // Walk the back cursor one step toward the front. Char ranges cannot use
+        // the arithmetic `Value` ops, so step the codepoint by `-incr` directly.
+        if let Some(back_char) = as_char(&yielded) {
+            let incr = match &self.incr {
+                Value::Int { val, .. } => *val,
+                _ => {
+                    self.done = true;
+                    return Some(Value::Error {
+                        error: ShellError::CannotCreateRange(self.span),
+                    });
+                }
             };
-            std::mem::swap(&mut self.curr, &mut next);
 
-            Some(next)
+            match (back_char as i64)
+                .checked_sub(incr)
+                .and_then(|cp| u32::try_from(cp).ok())
+                .and_then(char::from_u32)
+            {
+                Some(prev) => {
+                    self.back = Some(Value::String {
+                        val: prev.to_string(),
+                        span: self.span,
+                    })
+                }
+                // Stepped past a valid Unicode scalar value; this was the last
+                // element, so terminate after yielding it.
+                None => self.done = true,
+            }
         } else {
-            None
+            match yielded.sub(self.span, &self.incr) {
+                Ok(prev) => self.back = Some(prev),
+                Err(error) => {
+                    self.done = true;
+                    return Some(Value::Error { error });
+                }
+            }
         }
+
+        Some(yielded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(val: i64) -> Value {
+        Value::Int {
+            val,
+            span: Span::unknown(),
+        }
+    }
+
+    fn float(val: f64) -> Value {
+        Value::Float {
+            val,
+            span: Span::unknown(),
+        }
+    }
+
+    fn string(val: &str) -> Value {
+        Value::String {
+            val: val.to_string(),
+            span: Span::unknown(),
+        }
+    }
+
+    fn iter(from: Value, incr: Value, to: Value, inclusion: RangeInclusion) -> RangeIterator {
+        Range {
+            from,
+            incr,
+            to,
+            inclusion,
+        }
+        .into_iter()
+    }
+
+    fn as_i64(val: &Value) -> i64 {
+        match val {
+            Value::Int { val, .. } => *val,
+            other => panic!("expected int, got {other:?}"),
+        }
+    }
+
+    fn ints(iter: impl Iterator<Item = Value>) -> Vec<i64> {
+        iter.map(|v| as_i64(&v)).collect()
+    }
+
+    fn floats(iter: impl Iterator<Item = Value>) -> Vec<f64> {
+        iter.map(|v| match v {
+            Value::Float { val, .. } => val,
+            other => panic!("expected float, got {other:?}"),
+        })
+        .collect()
+    }
+
+    fn chars(iter: impl Iterator<Item = Value>) -> String {
+        iter.map(|v| match v {
+            Value::String { val, .. } => val,
+            other => panic!("expected string, got {other:?}"),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn int_range_iterates_forward_and_reverse() {
+        let forward = iter(int(1), int(1), int(5), RangeInclusion::Inclusive);
+        assert_eq!(ints(forward), vec![1, 2, 3, 4, 5]);
+
+        let reverse = iter(int(1), int(1), int(5), RangeInclusion::Inclusive);
+        assert_eq!(ints(reverse.rev()), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn int_range_size_hint_is_exact_after_next_back() {
+        // Exclusive `1..5` yields [1, 2, 3, 4]; draining one from the back must
+        // leave an exact count of 3 for the remaining front elements.
+        let mut range = iter(int(1), int(1), int(5), RangeInclusion::Exclusive);
+        assert_eq!(range.next_back().as_ref().map(as_i64), Some(4));
+        assert_eq!(range.size_hint(), (3, Some(3)));
+        assert_eq!(ints(range), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn float_range_iterates_forward_and_reverse() {
+        let forward = iter(float(1.0), float(1.0), float(3.0), RangeInclusion::Inclusive);
+        assert_eq!(floats(forward), vec![1.0, 2.0, 3.0]);
+
+        let reverse = iter(float(1.0), float(1.0), float(3.0), RangeInclusion::Inclusive);
+        assert_eq!(floats(reverse.rev()), vec![3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn char_range_iterates_forward_and_reverse() {
+        let forward = iter(string("a"), int(1), string("e"), RangeInclusion::Inclusive);
+        assert_eq!(chars(forward), "abcde");
+
+        // Reverse char ranges must walk every element, not stop after the last
+        // one with an error.
+        let reverse = iter(string("a"), int(1), string("e"), RangeInclusion::Inclusive);
+        assert_eq!(chars(reverse.rev()), "edcba");
+    }
+
+    #[test]
+    fn char_range_mixes_front_and_back() {
+        let mut range = iter(string("a"), int(1), string("e"), RangeInclusion::Inclusive);
+        assert_eq!(chars(range.next().into_iter()), "a");
+        assert_eq!(chars(range.next_back().into_iter()), "e");
+        assert_eq!(chars(range), "bcd");
     }
 }